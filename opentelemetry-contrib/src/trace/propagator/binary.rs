@@ -0,0 +1,273 @@
+//! # Binary Propagator
+//!
+//! OpenCensus/gRPC compatible binary propagation of `SpanContext`s, commonly known as the
+//! `grpc-trace-bin` format.
+//!
+//! The wire format is a leading version byte (currently always `0`), followed by a sequence
+//! of `field-id, field-value` pairs:
+//!
+//! * `0x00` followed by the 16-byte trace ID.
+//! * `0x01` followed by the 8-byte (big-endian) span ID.
+//! * `0x02` followed by a single trace-options byte, whose low bit is the sampled flag.
+//!
+//! Fields may appear in any order, decoding stops gracefully once the buffer is exhausted,
+//! and a missing trace-options field is treated as not-sampled.
+use opentelemetry::{
+    propagation::{text_map_propagator::FieldIter, Extractor, Injector, TextMapPropagator},
+    trace::{SpanContext, SpanId, TraceContextExt, TraceId, TraceState, TRACE_FLAG_SAMPLED},
+    Context,
+};
+
+const GRPC_TRACE_BIN_HEADER: &str = "grpc-trace-bin";
+
+const VERSION_ID: u8 = 0;
+const TRACE_ID_FIELD_ID: u8 = 0;
+const SPAN_ID_FIELD_ID: u8 = 1;
+const TRACE_OPTION_FIELD_ID: u8 = 2;
+
+lazy_static::lazy_static! {
+    static ref GRPC_TRACE_BIN_HEADER_FIELD: [String; 1] = [GRPC_TRACE_BIN_HEADER.to_string()];
+}
+
+/// Extracts and injects `SpanContext`s into `Extractor`s or `Injector`s using the
+/// `grpc-trace-bin` binary format.
+///
+/// Extracts and injects values to/from the `grpc-trace-bin` key. Since `Injector` and
+/// `Extractor` are string based, the encoded bytes are carried one byte per `char` rather
+/// than being further text-encoded.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::global;
+/// use opentelemetry_contrib::trace::propagator::binary::BinaryPropagator;
+///
+/// global::set_text_map_propagator(BinaryPropagator::new());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BinaryPropagator {
+    _private: (),
+}
+
+impl BinaryPropagator {
+    /// Create a new `BinaryPropagator`.
+    pub fn new() -> Self {
+        BinaryPropagator::default()
+    }
+
+    fn encode_span_context(&self, span_context: &SpanContext) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(29);
+        bytes.push(VERSION_ID);
+
+        bytes.push(TRACE_ID_FIELD_ID);
+        bytes.extend_from_slice(&span_context.trace_id().to_bytes());
+
+        bytes.push(SPAN_ID_FIELD_ID);
+        bytes.extend_from_slice(&span_context.span_id().to_bytes());
+
+        bytes.push(TRACE_OPTION_FIELD_ID);
+        bytes.push(span_context.trace_flags() & TRACE_FLAG_SAMPLED);
+
+        bytes
+    }
+
+    fn decode_span_context(&self, bytes: &[u8]) -> Result<SpanContext, ()> {
+        if bytes.is_empty() || bytes[0] != VERSION_ID {
+            return Err(());
+        }
+
+        let mut trace_id = TraceId::invalid();
+        let mut span_id = SpanId::invalid();
+        let mut trace_flags = 0u8;
+
+        let mut pos = 1;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                TRACE_ID_FIELD_ID => {
+                    let end = pos + 17;
+                    let field = bytes.get(pos + 1..end).ok_or(())?;
+                    trace_id = TraceId::from_bytes(field.try_into().map_err(|_| ())?);
+                    pos = end;
+                }
+                SPAN_ID_FIELD_ID => {
+                    let end = pos + 9;
+                    let field = bytes.get(pos + 1..end).ok_or(())?;
+                    span_id = SpanId::from_bytes(field.try_into().map_err(|_| ())?);
+                    pos = end;
+                }
+                TRACE_OPTION_FIELD_ID => {
+                    trace_flags = *bytes.get(pos + 1).ok_or(())? & TRACE_FLAG_SAMPLED;
+                    pos += 2;
+                }
+                // Unknown field id: nothing more we can safely decode.
+                _ => break,
+            }
+        }
+
+        Ok(SpanContext::new(
+            trace_id,
+            span_id,
+            trace_flags,
+            true,
+            TraceState::default(),
+        ))
+    }
+}
+
+impl TextMapPropagator for BinaryPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span_context = cx.span().span_context();
+        if span_context.is_valid() {
+            let encoded: String = self
+                .encode_span_context(span_context)
+                .into_iter()
+                .map(char::from)
+                .collect();
+            injector.set(GRPC_TRACE_BIN_HEADER, encoded);
+        }
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        let header_value = match extractor.get(GRPC_TRACE_BIN_HEADER) {
+            Some(value) => value,
+            None => return cx.clone(),
+        };
+        let bytes: Vec<u8> = header_value.chars().map(|c| c as u8).collect();
+
+        self.decode_span_context(&bytes)
+            .map(|sc| cx.with_remote_span_context(sc))
+            .unwrap_or_else(|_| cx.clone())
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(GRPC_TRACE_BIN_HEADER_FIELD.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TRACE_FLAG_NOT_SAMPLED;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestCarrier(HashMap<String, String>);
+
+    impl Extractor for TestCarrier {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).map(String::as_str)
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(String::as_str).collect()
+        }
+    }
+
+    impl Injector for TestCarrier {
+        fn set(&mut self, key: &str, value: String) {
+            self.0.insert(key.to_string(), value);
+        }
+    }
+
+    const TRACE_ID_BYTES: [u8; 16] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    ];
+    const SPAN_ID_BYTES: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    #[test]
+    fn decodes_known_byte_vector() {
+        let propagator = BinaryPropagator::new();
+        #[rustfmt::skip]
+        let bytes = vec![
+            0x00,
+            0x00, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            0x01, 1, 2, 3, 4, 5, 6, 7, 8,
+            0x02, 0x01,
+        ];
+
+        let span_context = propagator.decode_span_context(&bytes).unwrap();
+        assert_eq!(span_context.trace_id(), TraceId::from_bytes(TRACE_ID_BYTES));
+        assert_eq!(span_context.span_id(), SpanId::from_bytes(SPAN_ID_BYTES));
+        assert_eq!(span_context.trace_flags(), TRACE_FLAG_SAMPLED);
+    }
+
+    #[test]
+    fn missing_trace_options_defaults_to_not_sampled() {
+        let propagator = BinaryPropagator::new();
+        #[rustfmt::skip]
+        let bytes = vec![
+            0x00,
+            0x00, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            0x01, 1, 2, 3, 4, 5, 6, 7, 8,
+        ];
+
+        let span_context = propagator.decode_span_context(&bytes).unwrap();
+        assert_eq!(span_context.trace_flags(), TRACE_FLAG_NOT_SAMPLED);
+    }
+
+    #[test]
+    fn fields_in_any_order_decode_the_same() {
+        let propagator = BinaryPropagator::new();
+        #[rustfmt::skip]
+        let reordered = vec![
+            0x00,
+            0x02, 0x01,
+            0x01, 1, 2, 3, 4, 5, 6, 7, 8,
+            0x00, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ];
+
+        let span_context = propagator.decode_span_context(&reordered).unwrap();
+        assert_eq!(span_context.trace_id(), TraceId::from_bytes(TRACE_ID_BYTES));
+        assert_eq!(span_context.span_id(), SpanId::from_bytes(SPAN_ID_BYTES));
+        assert_eq!(span_context.trace_flags(), TRACE_FLAG_SAMPLED);
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let propagator = BinaryPropagator::new();
+        assert!(propagator.decode_span_context(&[0x01]).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_injector_and_extractor() {
+        let propagator = BinaryPropagator::new();
+        let span_context =
+            SpanContext::new(
+                TraceId::from_bytes(TRACE_ID_BYTES),
+                SpanId::from_bytes(SPAN_ID_BYTES),
+                TRACE_FLAG_SAMPLED,
+                true,
+                TraceState::default(),
+            );
+        let cx = Context::new().with_remote_span_context(span_context.clone());
+
+        let mut carrier = TestCarrier::default();
+        propagator.inject_context(&cx, &mut carrier);
+
+        let extracted_cx = propagator.extract_with_context(&Context::new(), &carrier);
+        let extracted = extracted_cx.span().span_context();
+
+        assert_eq!(extracted.trace_id(), span_context.trace_id());
+        assert_eq!(extracted.span_id(), span_context.span_id());
+        assert_eq!(extracted.trace_flags(), span_context.trace_flags());
+    }
+
+    #[test]
+    fn missing_header_preserves_given_context() {
+        let propagator = BinaryPropagator::new();
+        let span_context = SpanContext::new(
+            TraceId::from_bytes(TRACE_ID_BYTES),
+            SpanId::from_bytes(SPAN_ID_BYTES),
+            TRACE_FLAG_SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let cx = Context::new().with_remote_span_context(span_context.clone());
+
+        let extracted_cx = propagator.extract_with_context(&cx, &TestCarrier::default());
+        let extracted = extracted_cx.span().span_context();
+
+        assert_eq!(extracted.trace_id(), span_context.trace_id());
+        assert_eq!(extracted.span_id(), span_context.span_id());
+    }
+}
@@ -0,0 +1,152 @@
+//! # Composite Propagator
+//!
+//! Combines several [`TextMapPropagator`]s into one, so they can be layered for extraction
+//! fallback while all of them take part in injection.
+use opentelemetry::propagation::{
+    text_map_propagator::FieldIter, Extractor, Injector, TextMapPropagator,
+};
+use opentelemetry::Context;
+
+/// A [`TextMapPropagator`] composed of an ordered list of child propagators.
+///
+/// On `extract_with_context`, each child runs in turn over the context returned by the
+/// previous one, so later propagators can refine or override what earlier ones produced.
+/// `inject_context` calls every child, and `fields()` returns the union of all children's
+/// fields, deduplicated.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::sdk::propagation::TraceContextPropagator;
+/// use opentelemetry_contrib::trace::propagator::{
+///     composite::CompositeTextMapPropagator, gcp::CloudTraceOneWayPropagator,
+/// };
+///
+/// let _propagator = CompositeTextMapPropagator::new(vec![
+///     Box::new(CloudTraceOneWayPropagator),
+///     Box::new(TraceContextPropagator::new()),
+/// ]);
+/// ```
+#[derive(Debug, Default)]
+pub struct CompositeTextMapPropagator {
+    propagators: Vec<Box<dyn TextMapPropagator + Send + Sync>>,
+    fields: Vec<String>,
+}
+
+impl CompositeTextMapPropagator {
+    /// Create a new `CompositeTextMapPropagator` from an ordered list of propagators.
+    pub fn new(propagators: Vec<Box<dyn TextMapPropagator + Send + Sync>>) -> Self {
+        let mut fields: Vec<String> = Vec::new();
+        for propagator in &propagators {
+            for field in propagator.fields() {
+                if !fields.iter().any(|existing| existing == field) {
+                    fields.push(field.to_string());
+                }
+            }
+        }
+
+        CompositeTextMapPropagator { propagators, fields }
+    }
+}
+
+impl TextMapPropagator for CompositeTextMapPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        for propagator in &self.propagators {
+            propagator.inject_context(cx, injector);
+        }
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        self.propagators
+            .iter()
+            .fold(cx.clone(), |cx, propagator| {
+                propagator.extract_with_context(&cx, extractor)
+            })
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(self.fields.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::propagator::{gcp::CloudTraceOneWayPropagator, jaeger::JaegerPropagator};
+    use opentelemetry::trace::TraceContextExt;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestCarrier(HashMap<String, String>);
+
+    impl Extractor for TestCarrier {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).map(String::as_str)
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(String::as_str).collect()
+        }
+    }
+
+    const GCP_HEADER: &str = "x-cloud-trace-context";
+    const JAEGER_HEADER: &str = "uber-trace-id";
+    const GCP_TRACE_ID: &str = "abcdefabcdefabcdefabcdefabcdefab";
+    const JAEGER_TRACE_ID: &str = "1234567890abcdef1234567890abcdef";
+
+    #[test]
+    fn falls_back_to_second_propagator_when_first_finds_nothing() {
+        let composite = CompositeTextMapPropagator::new(vec![
+            Box::new(CloudTraceOneWayPropagator),
+            Box::new(JaegerPropagator::new()),
+        ]);
+
+        let mut carrier = TestCarrier::default();
+        carrier.0.insert(
+            JAEGER_HEADER.to_string(),
+            format!("{}:1:0:1", JAEGER_TRACE_ID),
+        );
+
+        let cx = composite.extract_with_context(&Context::new(), &carrier);
+        assert_eq!(
+            cx.span().span_context().trace_id().to_hex(),
+            JAEGER_TRACE_ID
+        );
+    }
+
+    #[test]
+    fn does_not_clobber_a_successful_first_extraction() {
+        let composite = CompositeTextMapPropagator::new(vec![
+            Box::new(CloudTraceOneWayPropagator),
+            Box::new(JaegerPropagator::new()),
+        ]);
+
+        let mut carrier = TestCarrier::default();
+        carrier
+            .0
+            .insert(GCP_HEADER.to_string(), format!("{}/1;o=1", GCP_TRACE_ID));
+
+        let cx = composite.extract_with_context(&Context::new(), &carrier);
+        assert_eq!(cx.span().span_context().trace_id().to_hex(), GCP_TRACE_ID);
+    }
+
+    #[test]
+    fn reversed_order_keeps_an_earlier_extraction_when_later_finds_nothing() {
+        let composite = CompositeTextMapPropagator::new(vec![
+            Box::new(JaegerPropagator::new()),
+            Box::new(CloudTraceOneWayPropagator),
+        ]);
+
+        let mut carrier = TestCarrier::default();
+        carrier.0.insert(
+            JAEGER_HEADER.to_string(),
+            format!("{}:1:0:1", JAEGER_TRACE_ID),
+        );
+
+        let cx = composite.extract_with_context(&Context::new(), &carrier);
+        assert_eq!(
+            cx.span().span_context().trace_id().to_hex(),
+            JAEGER_TRACE_ID
+        );
+    }
+}
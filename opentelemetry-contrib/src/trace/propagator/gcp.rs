@@ -10,6 +10,8 @@ use opentelemetry::{
 
 const GCP_CLOUD_TRACE_HEADER: &str = "x-cloud-trace-context";
 
+const GCP_CLOUD_TRACE_HEADER_FIELDS_EMPTY: [String; 0] = [];
+
 lazy_static::lazy_static! {
     static ref GCP_CLOUD_TRACE_HEADER_FIELD: [String; 1] = [GCP_CLOUD_TRACE_HEADER.to_string()];
 }
@@ -101,3 +103,77 @@ impl TextMapPropagator for CloudTracePropagator {
         FieldIter::new(GCP_CLOUD_TRACE_HEADER_FIELD.as_ref())
     }
 }
+
+/// Extracts `SpanContext`s from the GCP Cloud Trace header, but never injects it.
+///
+/// GCP frontends and load balancers stamp inbound requests with `x-cloud-trace-context`,
+/// so it's useful to adopt that trace/span on extraction. On egress though, we usually
+/// want a different, standards-based propagator (e.g. `TraceContextPropagator`) to own
+/// the outgoing headers. Compose this after that propagator (later propagators override
+/// earlier ones on extraction) so the GCP header, when present, takes precedence while
+/// injection is left entirely to the other propagator.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::sdk::propagation::TraceContextPropagator;
+/// use opentelemetry_contrib::trace::propagator::gcp::CloudTraceOneWayPropagator;
+///
+/// let _propagator = CloudTraceOneWayPropagator;
+/// let _trace_context_propagator = TraceContextPropagator::new();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CloudTraceOneWayPropagator;
+
+impl TextMapPropagator for CloudTraceOneWayPropagator {
+    fn inject_context(&self, _cx: &Context, _injector: &mut dyn Injector) {}
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        CloudTracePropagator
+            .extract_span_context(extractor)
+            .map(|sc| cx.with_remote_span_context(sc))
+            .unwrap_or_else(|_| cx.clone())
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(GCP_CLOUD_TRACE_HEADER_FIELDS_EMPTY.as_ref())
+    }
+}
+
+/// The Cloud Logging fields that stitch a log entry to the span it was emitted from.
+///
+/// Add these fields to a structured (JSON) log entry so Cloud Logging displays it under the
+/// matching span in the Cloud Trace UI. See the [Cloud Logging docs][logging-trace] for the
+/// field names.
+///
+/// [logging-trace]: https://cloud.google.com/logging/docs/structured-logging#special-payload-fields
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloudLoggingTraceContext {
+    /// Value for the `logging.googleapis.com/trace` field.
+    pub trace: String,
+    /// Value for the `logging.googleapis.com/spanId` field.
+    pub span_id: String,
+    /// Value for the `logging.googleapis.com/trace_sampled` field.
+    pub trace_sampled: bool,
+}
+
+impl CloudTracePropagator {
+    /// Build the [`CloudLoggingTraceContext`] for the current span in `cx`, so a structured
+    /// log entry can be linked to it. Returns `None` if `cx` has no valid span.
+    pub fn logging_fields(cx: &Context, project_id: &str) -> Option<CloudLoggingTraceContext> {
+        let span_context = cx.span().span_context();
+        if !span_context.is_valid() {
+            return None;
+        }
+
+        Some(CloudLoggingTraceContext {
+            trace: format!(
+                "projects/{}/traces/{}",
+                project_id,
+                span_context.trace_id().to_hex()
+            ),
+            span_id: format!("{:016x}", span_context.span_id().to_u64()),
+            trace_sampled: span_context.is_sampled(),
+        })
+    }
+}
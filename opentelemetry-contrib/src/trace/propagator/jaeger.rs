@@ -0,0 +1,249 @@
+//! # Jaeger Propagator
+//!
+//! Propagates trace context in the Jaeger `uber-trace-id` header format.
+use std::borrow::Cow;
+
+use opentelemetry::{
+    baggage::BaggageExt,
+    propagation::{text_map_propagator::FieldIter, Extractor, Injector, TextMapPropagator},
+    trace::{
+        SpanContext, SpanId, TraceContextExt, TraceId, TraceState, TRACE_FLAG_NOT_SAMPLED,
+        TRACE_FLAG_SAMPLED,
+    },
+    Context, KeyValue,
+};
+
+const JAEGER_HEADER: &str = "uber-trace-id";
+const JAEGER_BAGGAGE_PREFIX: &str = "uberctx-";
+
+const DEBUG_FLAG: u8 = 0x02;
+
+lazy_static::lazy_static! {
+    static ref JAEGER_HEADER_FIELD: [String; 1] = [JAEGER_HEADER.to_string()];
+}
+
+/// Propagates `SpanContext`s in the Jaeger `uber-trace-id` header format.
+///
+/// The header has the form `{trace-id}:{span-id}:{parent-span-id}:{flags}`, where
+/// `trace-id` and `span-id` are hex encoded (`trace-id` may be 64- or 128-bit),
+/// `parent-span-id` is usually `0`, and `flags` is a bit field where `0x01` means sampled
+/// and `0x02` means debug. Baggage carried in `uberctx-<key>` headers is extracted into the
+/// OpenTelemetry `Context` as well.
+///
+/// ## Example
+///
+/// ```
+/// use opentelemetry::global;
+/// use opentelemetry_contrib::trace::propagator::jaeger::JaegerPropagator;
+///
+/// global::set_text_map_propagator(JaegerPropagator::new());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct JaegerPropagator {
+    _private: (),
+}
+
+impl JaegerPropagator {
+    /// Create a new `JaegerPropagator`.
+    pub fn new() -> Self {
+        JaegerPropagator::default()
+    }
+
+    fn extract_span_context(&self, extractor: &dyn Extractor) -> Result<SpanContext, ()> {
+        let header_value = extractor.get(JAEGER_HEADER).unwrap_or("").trim();
+        let parts: Vec<&str> = header_value.split(':').collect();
+        if parts.len() != 4 {
+            return Err(());
+        }
+
+        let trace_id = Self::parse_trace_id(parts[0])?;
+        let span_id = Self::parse_span_id(parts[1])?;
+        let flags = u8::from_str_radix(parts[3], 16).map_err(|_| ())?;
+
+        if trace_id.to_u128() == 0 || span_id.to_u64() == 0 {
+            return Err(());
+        }
+
+        let trace_flags = if flags & TRACE_FLAG_SAMPLED == TRACE_FLAG_SAMPLED
+            || flags & DEBUG_FLAG == DEBUG_FLAG
+        {
+            TRACE_FLAG_SAMPLED
+        } else {
+            TRACE_FLAG_NOT_SAMPLED
+        };
+
+        Ok(SpanContext::new(
+            trace_id,
+            span_id,
+            trace_flags,
+            true,
+            TraceState::default(),
+        ))
+    }
+
+    fn parse_trace_id(value: &str) -> Result<TraceId, ()> {
+        if value.is_empty() || value.len() > 32 {
+            return Err(());
+        }
+        let padded: Cow<'_, str> = if value.len() < 32 {
+            Cow::Owned(format!("{:0>32}", value))
+        } else {
+            Cow::Borrowed(value)
+        };
+        let trace_id = TraceId::from_hex(&padded);
+        if trace_id.to_u128() == 0 {
+            return Err(());
+        }
+        Ok(trace_id)
+    }
+
+    fn parse_span_id(value: &str) -> Result<SpanId, ()> {
+        let id = u64::from_str_radix(value, 16).map_err(|_| ())?;
+        Ok(SpanId::from_u64(id))
+    }
+
+    fn extract_baggage(&self, extractor: &dyn Extractor) -> Vec<KeyValue> {
+        extractor
+            .keys()
+            .into_iter()
+            .filter_map(|key| {
+                let value = extractor.get(key)?;
+                key.strip_prefix(JAEGER_BAGGAGE_PREFIX)
+                    .map(|baggage_key| KeyValue::new(baggage_key.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+}
+
+impl TextMapPropagator for JaegerPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span_context = cx.span().span_context();
+        if span_context.is_valid() {
+            let flags = if span_context.is_sampled() {
+                TRACE_FLAG_SAMPLED
+            } else {
+                0
+            };
+
+            injector.set(
+                JAEGER_HEADER,
+                format!(
+                    "{}:{}:0:{:x}",
+                    span_context.trace_id().to_hex(),
+                    span_context.span_id().to_hex(),
+                    flags,
+                ),
+            );
+        }
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        let cx = self
+            .extract_span_context(extractor)
+            .map(|sc| cx.with_remote_span_context(sc))
+            .unwrap_or_else(|_| cx.clone());
+
+        cx.with_baggage(self.extract_baggage(extractor))
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(JAEGER_HEADER_FIELD.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestCarrier(HashMap<String, String>);
+
+    impl Extractor for TestCarrier {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).map(String::as_str)
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(String::as_str).collect()
+        }
+    }
+
+    #[test]
+    fn parses_64_bit_and_128_bit_trace_ids() {
+        let trace_id_64 = JaegerPropagator::parse_trace_id("1234567890abcdef").unwrap();
+        let trace_id_128 =
+            JaegerPropagator::parse_trace_id("11112222333344445555666677778888").unwrap();
+
+        assert_eq!(trace_id_64.to_hex(), "00000000000000001234567890abcdef");
+        assert_eq!(trace_id_128.to_hex(), "11112222333344445555666677778888");
+    }
+
+    #[test]
+    fn rejects_empty_and_all_zero_trace_ids() {
+        assert!(JaegerPropagator::parse_trace_id("").is_err());
+        assert!(JaegerPropagator::parse_trace_id("0").is_err());
+    }
+
+    #[test]
+    fn sampled_and_debug_flags_both_force_sampled() {
+        let propagator = JaegerPropagator::new();
+
+        let carrier = |flags: &str| {
+            let mut carrier = TestCarrier::default();
+            carrier.0.insert(
+                JAEGER_HEADER.to_string(),
+                format!("1234567890abcdef1234567890abcdef:1234567890abcdef:0:{}", flags),
+            );
+            carrier
+        };
+
+        let sampled = propagator
+            .extract_span_context(&carrier("1"))
+            .unwrap();
+        assert_eq!(sampled.trace_flags(), TRACE_FLAG_SAMPLED);
+
+        let debug = propagator.extract_span_context(&carrier("2")).unwrap();
+        assert_eq!(debug.trace_flags(), TRACE_FLAG_SAMPLED);
+
+        let neither = propagator.extract_span_context(&carrier("0")).unwrap();
+        assert_eq!(neither.trace_flags(), TRACE_FLAG_NOT_SAMPLED);
+    }
+
+    #[test]
+    fn extracts_uberctx_baggage() {
+        let propagator = JaegerPropagator::new();
+        let mut carrier = TestCarrier::default();
+        carrier
+            .0
+            .insert("uberctx-user-id".to_string(), "42".to_string());
+        carrier
+            .0
+            .insert("some-other-header".to_string(), "ignored".to_string());
+
+        let baggage = propagator.extract_baggage(&carrier);
+
+        assert_eq!(baggage.len(), 1);
+        assert_eq!(baggage[0].key.as_str(), "user-id");
+        assert_eq!(baggage[0].value.as_str(), "42");
+    }
+
+    #[test]
+    fn missing_header_preserves_given_context() {
+        let propagator = JaegerPropagator::new();
+        let span_context = SpanContext::new(
+            TraceId::from_hex("1234567890abcdef1234567890abcdef"),
+            SpanId::from_u64(0x1234567890abcdef),
+            TRACE_FLAG_SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let cx = Context::new().with_remote_span_context(span_context.clone());
+
+        let extracted_cx = propagator.extract_with_context(&cx, &TestCarrier::default());
+        let extracted = extracted_cx.span().span_context();
+
+        assert_eq!(extracted.trace_id(), span_context.trace_id());
+        assert_eq!(extracted.span_id(), span_context.span_id());
+    }
+}
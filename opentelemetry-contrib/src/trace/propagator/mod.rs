@@ -7,5 +7,10 @@
 //!
 //! * `binary_propagator`, propagating trace context in the binary format.
 //! * `CloudTracePropagator`, propagating trace context in the the GCP Cloud Trace format.
+//! * `CloudTraceOneWayPropagator`, extracting the GCP Cloud Trace format without ever injecting it.
+//! * `JaegerPropagator`, propagating trace context in the Jaeger `uber-trace-id` format.
+//! * `CompositeTextMapPropagator`, combining several of the propagators above into one.
 pub mod binary;
+pub mod composite;
 pub mod gcp;
+pub mod jaeger;